@@ -6,75 +6,280 @@ use core::{
 use std::fmt;
 
 use num_bigint::BigUint;
-use p3_field::{exp_u64_by_squaring, halve_u32, AbstractField, Field, Packable};
+use p3_field::{exp_u64_by_squaring, halve_u32, AbstractField, Field, Packable, TwoAdicField};
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+// Montgomery parameters for R = 2^MONTY_BITS, shared by every `MontyField<P>`.
+const MONTY_BITS: u32 = 7;
+const MONTY_MASK: u32 = (1 << MONTY_BITS) - 1;
+const MONTY_R: u32 = 1 << MONTY_BITS;
+
+/// Extended Euclidean algorithm, usable in `const` context: `a^{-1} mod m`.
+const fn const_mod_inverse(a: u32, m: u32) -> u32 {
+  let (mut old_r, mut r) = (a as i64, m as i64);
+  let (mut old_s, mut s) = (1i64, 0i64);
+  while r != 0 {
+    let q = old_r / r;
+    let new_r = old_r - q * r;
+    old_r = r;
+    r = new_r;
+    let new_s = old_s - q * s;
+    old_s = s;
+    s = new_s;
+  }
+  let m = m as i64;
+  (((old_s % m) + m) % m) as u32
+}
+
+/// Trial-division primality test, usable in `const` context. `P` is always small enough
+/// (`< 128`, see `MONTGOMERY_PRECONDITION`) for this to be cheap at compile time.
+const fn is_prime(n: u32) -> bool {
+  if n < 2 {
+    return false;
+  }
+  let mut d = 2;
+  while d * d <= n {
+    if n % d == 0 {
+      return false;
+    }
+    d += 1;
+  }
+  true
+}
+
+/// `-P^{-1} mod R`, the Montgomery reduction constant for a given prime `P`.
+const fn monty_mu<const P: u32>() -> u32 { (MONTY_R - const_mod_inverse(P, MONTY_R)) % MONTY_R }
+
+/// `R^2 mod P`, used to carry canonical values into Montgomery form.
+const fn monty_r2<const P: u32>() -> u32 { ((MONTY_R as u64 * MONTY_R as u64) % P as u64) as u32 }
+
+/// Montgomery reduction: given `t`, returns `t * R^{-1} mod P`.
+#[inline]
+fn montgomery_reduce<const P: u32>(t: u32) -> u32 {
+  let m = (t & MONTY_MASK).wrapping_mul(monty_mu::<P>()) & MONTY_MASK;
+  let u = (t + m * P) >> MONTY_BITS;
+  if u >= P {
+    u - P
+  } else {
+    u
+  }
+}
 
-const PLUTO_FIELD_PRIME: u32 = 101;
-// const MONTY_BITS: u32 = 7;
-// const MONTY_MASK: u32 = (1 << MONTY_BITS) - 1;
-// const MONTY_MU: u32 = 80;
+/// Modular exponentiation by repeated squaring, operating on canonical values.
+fn pow_mod<const P: u32>(mut base: u32, mut exp: u32) -> u32 {
+  let mut result = 1u32;
+  base %= P;
+  while exp > 0 {
+    if exp & 1 == 1 {
+      result = result * base % P;
+    }
+    base = base * base % P;
+    exp >>= 1;
+  }
+  result
+}
 
+/// A field element of `F_P`, stored internally in Montgomery form (i.e. `value`
+/// holds `a * R mod P`, not `a` itself).
 #[derive(Copy, Clone, Default, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
-pub struct PlutoField {
+pub struct MontyField<const P: u32> {
   value: u32,
 }
 
+/// The toy 101-element field the rest of this crate is built on.
+pub type PlutoField = MontyField<101>;
+
+impl<const P: u32> MontyField<P> {
+  pub const ORDER_U32: u32 = P;
+
+  /// `montgomery_reduce` only leaves a canonical result when every product of two
+  /// reduced values stays below `R·P`, i.e. `(P-1)^2 < R·P`. `R` is the single fixed
+  /// `MONTY_R`, not derived per `P`, so this bounds which primes `MontyField<P>` may
+  /// safely be instantiated with (`P < 128`, in practice up to 127). `R` is also a power
+  /// of two, so `P` must be odd for it to be invertible mod `R` (`monty_mu`/`monty_r2`
+  /// silently collapse otherwise). `P` must also actually be prime — `MontyField<P>` is
+  /// a field, and a composite `P` has zero divisors that silently break `try_inverse`
+  /// (e.g. `MontyField::<9>::new(3)` would claim an inverse that doesn't exist).
+  /// Referenced from `new` (and so from `zero`, `one` and the other constructors), so
+  /// any code that reaches `new` with a `P` violating one of these constraints fails to
+  /// compile; code that only uses `Default` or the operators on such a `P` is not checked.
+  const MONTGOMERY_PRECONDITION: () = assert!(
+    P % 2 == 1 && is_prime(P) && (P as u64 - 1) * (P as u64 - 1) < MONTY_R as u64 * P as u64,
+    "MontyField<P>: P must be an odd prime < 128 for the fixed Montgomery modulus R = \
+     2^MONTY_BITS"
+  );
+
+  /// Builds a field element from a canonical value, carrying it into Montgomery form.
+  ///
+  /// `value` is reduced mod `P` first, so out-of-range inputs wrap instead of
+  /// overflowing the Montgomery multiply or aliasing to the wrong Montgomery
+  /// representative.
+  pub fn new(value: u32) -> Self {
+    let () = Self::MONTGOMERY_PRECONDITION;
+    Self { value: montgomery_reduce::<P>((value % P) * monty_r2::<P>()) }
+  }
+
+  /// Leaves Montgomery form, returning the canonical representative in `[0, P)`.
+  pub fn to_canonical_u32(&self) -> u32 { montgomery_reduce::<P>(self.value) }
+
+  /// Modular inverse with no early return on zero: the exponentiation always runs to
+  /// completion and the zero check is folded in via `ct_eq`. The conditional final
+  /// subtraction in `montgomery_reduce` (reached through `to_canonical_u32` and `new`)
+  /// is still a branch on the reduced value.
+  pub fn ct_try_inverse(&self) -> CtOption<Self> {
+    let result = pow_mod::<P>(self.to_canonical_u32(), P - 2); // a^(p - 2)
+    CtOption::new(Self::new(result), !self.ct_eq(&Self::zero()))
+  }
+
+  /// Inverts every nonzero element of `elems` in place, using Montgomery's trick to
+  /// amortize the whole slice over a single [`Field::try_inverse`] call. Zero entries
+  /// are left untouched.
+  pub fn batch_inverse(elems: &mut [Self]) {
+    let mut scratch = vec![Self::one(); elems.len()];
+    let mut acc = Self::one();
+    for (scratch_i, elem) in scratch.iter_mut().zip(elems.iter()) {
+      *scratch_i = acc;
+      if !elem.is_zero() {
+        acc *= *elem;
+      }
+    }
+
+    let mut acc_inv = acc.inverse();
+    for (elem, scratch_i) in elems.iter_mut().zip(scratch.iter()).rev() {
+      if !elem.is_zero() {
+        let inv = acc_inv * *scratch_i;
+        acc_inv *= *elem;
+        *elem = inv;
+      }
+    }
+  }
+
+  /// Non-mutating variant of [`Self::batch_inverse`].
+  pub fn batch_inverse_to_vec(elems: &[Self]) -> Vec<Self> {
+    let mut out = elems.to_vec();
+    Self::batch_inverse(&mut out);
+    out
+  }
+}
+
 impl PlutoField {
-  pub const ORDER_U32: u32 = PLUTO_FIELD_PRIME;
+  /// The Legendre symbol of `self`: `0` if `self` is zero, `1` if it's a nonzero
+  /// square, `-1` otherwise.
+  pub fn legendre(&self) -> i32 {
+    if self.is_zero() {
+      return 0;
+    }
+    match pow_mod::<101>(self.to_canonical_u32(), (Self::ORDER_U32 - 1) / 2) {
+      1 => 1,
+      _ => -1,
+    }
+  }
 
-  pub fn new(value: u32) -> Self { Self { value } }
+  /// The modular square root via Tonelli–Shanks, or `None` if `self` is not a square.
+  pub fn sqrt(&self) -> Option<Self> {
+    if self.is_zero() {
+      return Some(Self::zero());
+    }
+    if self.legendre() == -1 {
+      return None;
+    }
+
+    // p - 1 = 2^S * Q, with Q odd.
+    const S: u32 = 2;
+    const Q: u32 = 25;
+
+    let mut c = pow_mod::<101>(quadratic_non_residue().to_canonical_u32(), Q);
+    let mut t = pow_mod::<101>(self.to_canonical_u32(), Q);
+    let mut r = pow_mod::<101>(self.to_canonical_u32(), Q.div_ceil(2));
+    let mut m = S;
+
+    loop {
+      if t == 1 {
+        return Some(Self::new(r));
+      }
+      let mut i = 1;
+      let mut t2i = t * t % Self::ORDER_U32;
+      while t2i != 1 {
+        t2i = t2i * t2i % Self::ORDER_U32;
+        i += 1;
+      }
+      let b = pow_mod::<101>(c, 1 << (m - i - 1));
+      r = r * b % Self::ORDER_U32;
+      c = b * b % Self::ORDER_U32;
+      t = t * c % Self::ORDER_U32;
+      m = i;
+    }
+  }
+
+  /// The full multiplicative subgroup generated by [`TwoAdicField::two_adic_generator`]:
+  /// `[1, g, g^2, ..., g^(2^bits - 1)]`.
+  pub fn two_adic_subgroup(bits: usize) -> Vec<Self> {
+    let generator = Self::two_adic_generator(bits);
+    let mut elem = Self::one();
+    let mut subgroup = Vec::with_capacity(1 << bits);
+    for _ in 0..1 << bits {
+      subgroup.push(elem);
+      elem *= generator;
+    }
+    subgroup
+  }
+}
+
+impl TwoAdicField for PlutoField {
+  // p - 1 = 100 = 2^2 * 25.
+  const TWO_ADICITY: usize = 2;
+
+  fn two_adic_generator(bits: usize) -> Self {
+    assert!(bits <= Self::TWO_ADICITY, "PlutoField has no primitive 2^{bits}-th root of unity");
+    let exponent = (Self::ORDER_U32 - 1) >> bits;
+    Self::new(pow_mod::<101>(Self::generator().to_canonical_u32(), exponent))
+  }
+}
+
+impl<const P: u32> fmt::Display for MontyField<P> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.to_canonical_u32()) }
 }
 
-impl fmt::Display for PlutoField {
-  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.value) }
+impl<const P: u32> ConstantTimeEq for MontyField<P> {
+  fn ct_eq(&self, other: &Self) -> Choice { self.to_canonical_u32().ct_eq(&other.to_canonical_u32()) }
 }
 
-impl Packable for PlutoField {}
+impl<const P: u32> ConditionallySelectable for MontyField<P> {
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    Self { value: u32::conditional_select(&a.value, &b.value, choice) }
+  }
+}
 
-impl Div for PlutoField {
+impl<const P: u32> Packable for MontyField<P> {}
+
+impl<const P: u32> Div for MontyField<P> {
   type Output = Self;
 
   #[allow(clippy::suspicious_arithmetic_impl)]
   fn div(self, rhs: Self) -> Self { self * rhs.inverse() }
 }
-impl Field for PlutoField {
+impl<const P: u32> Field for MontyField<P> {
   // TODO: Add cfg-guarded Packing for AVX2, NEON, etc.
   type Packing = Self;
 
-  fn is_zero(&self) -> bool { self.value == 0 || self.value == Self::ORDER_U32 }
+  fn is_zero(&self) -> bool { self.ct_eq(&Self::zero()).into() }
 
   #[inline]
   fn exp_u64_generic<AF: AbstractField<F = Self>>(val: AF, power: u64) -> AF {
     exp_u64_by_squaring(val, power)
   }
 
-  fn try_inverse(&self) -> Option<Self> {
-    if self.is_zero() {
-      return None;
-    }
-    let exponent = PLUTO_FIELD_PRIME - 2; // p - 2
-    let mut result = 1;
-    let mut base = self.value;
-    let mut power = exponent;
-
-    while power > 0 {
-      if power & 1 == 1 {
-        result = result * base % PLUTO_FIELD_PRIME;
-      }
-      base = base * base % PLUTO_FIELD_PRIME;
-      power >>= 1;
-    }
-    Some(Self { value: result })
-  }
+  fn try_inverse(&self) -> Option<Self> { self.ct_try_inverse().into() }
 
   #[inline]
-  fn halve(&self) -> Self { PlutoField::new(halve_u32::<PLUTO_FIELD_PRIME>(self.value)) }
+  fn halve(&self) -> Self { Self { value: halve_u32::<P>(self.value) } }
 
   #[inline]
-  fn order() -> BigUint { PLUTO_FIELD_PRIME.into() }
+  fn order() -> BigUint { P.into() }
 }
 
-impl AbstractField for PlutoField {
+impl<const P: u32> AbstractField for MontyField<P> {
   type F = Self;
 
   fn zero() -> Self { Self::new(0) }
@@ -98,62 +303,68 @@ impl AbstractField for PlutoField {
 
   #[inline]
   fn from_canonical_u64(n: u64) -> Self {
-    debug_assert!(n < PLUTO_FIELD_PRIME as u64);
+    debug_assert!(n < P as u64);
     Self::from_canonical_u32(n as u32)
   }
 
   #[inline]
   fn from_canonical_usize(n: usize) -> Self {
-    debug_assert!(n < PLUTO_FIELD_PRIME as usize);
+    debug_assert!(n < P as usize);
     Self::from_canonical_u32(n as u32)
   }
 
   #[inline]
-  fn from_wrapped_u32(n: u32) -> Self { Self { value: n % PLUTO_FIELD_PRIME } }
+  fn from_wrapped_u32(n: u32) -> Self { Self::new(n % P) }
 
   #[inline]
-  fn from_wrapped_u64(n: u64) -> Self { Self { value: (n % PLUTO_FIELD_PRIME as u64) as u32 } }
+  fn from_wrapped_u64(n: u64) -> Self { Self::new((n % P as u64) as u32) }
 
   // generator for multiplicative subgroup of the field
   fn generator() -> Self { Self::new(2) }
 }
 
-impl Mul for PlutoField {
+impl<const P: u32> Mul for MontyField<P> {
   type Output = Self;
 
-  fn mul(self, rhs: Self) -> Self { Self { value: (self.value * rhs.value) % 101 } }
+  fn mul(self, rhs: Self) -> Self { Self { value: montgomery_reduce::<P>(self.value * rhs.value) } }
 }
 
-impl Product for PlutoField {
+impl<const P: u32> Product for MontyField<P> {
   fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
     iter.reduce(|x, y| x * y).unwrap_or(Self::one())
   }
 }
 
-impl SubAssign for PlutoField {
+impl<const P: u32> SubAssign for MontyField<P> {
   fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
 }
 
-impl AddAssign for PlutoField {
+impl<const P: u32> AddAssign for MontyField<P> {
   fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
 }
 
-impl MulAssign for PlutoField {
+impl<const P: u32> MulAssign for MontyField<P> {
   fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
 }
 
-impl Neg for PlutoField {
+impl<const P: u32> Neg for MontyField<P> {
   type Output = Self;
 
-  fn neg(self) -> Self::Output { Self::new(Self::ORDER_U32 - self.value) }
+  fn neg(self) -> Self::Output {
+    if self.value == 0 {
+      self
+    } else {
+      Self { value: Self::ORDER_U32 - self.value }
+    }
+  }
 }
 
-impl Add for PlutoField {
+impl<const P: u32> Add for MontyField<P> {
   type Output = Self;
 
   fn add(self, rhs: Self) -> Self {
     let mut sum = self.value + rhs.value;
-    let (corr_sum, over) = sum.overflowing_sub(PLUTO_FIELD_PRIME);
+    let (corr_sum, over) = sum.overflowing_sub(P);
     if !over {
       sum = corr_sum;
     }
@@ -161,20 +372,170 @@ impl Add for PlutoField {
   }
 }
 
-impl Sum for PlutoField {
+impl<const P: u32> Sum for MontyField<P> {
   fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
     iter.reduce(|x, y| x + y).unwrap_or(Self::zero())
   }
 }
 
-impl Sub for PlutoField {
+impl<const P: u32> Sub for MontyField<P> {
   type Output = Self;
 
   fn sub(self, rhs: Self) -> Self {
     let (mut diff, over) = self.value.overflowing_sub(rhs.value);
-    let corr = if over { PLUTO_FIELD_PRIME } else { 0 };
+    let corr = if over { P } else { 0 };
     diff = diff.wrapping_add(corr);
-    Self::new(diff)
+    Self { value: diff }
+  }
+}
+
+/// The quadratic non-residue `W` defining `PlutoExt2 = PlutoField[x] / (x^2 - W)`.
+///
+/// `2` generates the full multiplicative group of `PlutoField` (order 100), so
+/// `2^50 = -1` and `2` is not a square.
+fn quadratic_non_residue() -> PlutoField { PlutoField::new(2) }
+
+/// An element of the quadratic extension `F_{101^2} = F_101[x] / (x^2 - W)`,
+/// represented as `c0 + c1 * x`.
+#[derive(Copy, Clone, Default, Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+pub struct PlutoExt2([PlutoField; 2]);
+
+impl PlutoExt2 {
+  pub fn new(c0: PlutoField, c1: PlutoField) -> Self { Self([c0, c1]) }
+
+  /// The Frobenius endomorphism `a0 + a1 * x -> a0 - a1 * x`.
+  pub fn frobenius(&self) -> Self { Self([self.0[0], -self.0[1]]) }
+
+  /// The field norm down to `PlutoField`: `a0^2 - W * a1^2`.
+  fn norm(&self) -> PlutoField { self.0[0] * self.0[0] - quadratic_non_residue() * self.0[1] * self.0[1] }
+}
+
+impl fmt::Display for PlutoExt2 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{} + {}*x", self.0[0], self.0[1]) }
+}
+
+impl Packable for PlutoExt2 {}
+
+impl Div for PlutoExt2 {
+  type Output = Self;
+
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Self) -> Self { self * rhs.inverse() }
+}
+
+impl Field for PlutoExt2 {
+  type Packing = Self;
+
+  fn is_zero(&self) -> bool { self.0[0].is_zero() && self.0[1].is_zero() }
+
+  #[inline]
+  fn exp_u64_generic<AF: AbstractField<F = Self>>(val: AF, power: u64) -> AF {
+    exp_u64_by_squaring(val, power)
+  }
+
+  fn try_inverse(&self) -> Option<Self> {
+    if self.is_zero() {
+      return None;
+    }
+    let norm_inv = self.norm().try_inverse()?;
+    Some(Self([self.0[0] * norm_inv, -self.0[1] * norm_inv]))
+  }
+
+  #[inline]
+  fn halve(&self) -> Self { Self([self.0[0].halve(), self.0[1].halve()]) }
+
+  #[inline]
+  fn order() -> BigUint { PlutoField::order() * PlutoField::order() }
+}
+
+impl AbstractField for PlutoExt2 {
+  type F = Self;
+
+  fn zero() -> Self { Self([PlutoField::zero(), PlutoField::zero()]) }
+
+  fn one() -> Self { Self([PlutoField::one(), PlutoField::zero()]) }
+
+  fn two() -> Self { Self([PlutoField::two(), PlutoField::zero()]) }
+
+  fn neg_one() -> Self { Self([PlutoField::neg_one(), PlutoField::zero()]) }
+
+  #[inline]
+  fn from_f(f: Self::F) -> Self { f }
+
+  fn from_bool(b: bool) -> Self { Self([PlutoField::from_bool(b), PlutoField::zero()]) }
+
+  fn from_canonical_u8(n: u8) -> Self { Self([PlutoField::from_canonical_u8(n), PlutoField::zero()]) }
+
+  fn from_canonical_u16(n: u16) -> Self { Self([PlutoField::from_canonical_u16(n), PlutoField::zero()]) }
+
+  fn from_canonical_u32(n: u32) -> Self { Self([PlutoField::from_canonical_u32(n), PlutoField::zero()]) }
+
+  #[inline]
+  fn from_canonical_u64(n: u64) -> Self { Self([PlutoField::from_canonical_u64(n), PlutoField::zero()]) }
+
+  #[inline]
+  fn from_canonical_usize(n: usize) -> Self { Self([PlutoField::from_canonical_usize(n), PlutoField::zero()]) }
+
+  #[inline]
+  fn from_wrapped_u32(n: u32) -> Self { Self([PlutoField::from_wrapped_u32(n), PlutoField::zero()]) }
+
+  #[inline]
+  fn from_wrapped_u64(n: u64) -> Self { Self([PlutoField::from_wrapped_u64(n), PlutoField::zero()]) }
+
+  // `2 + x` generates the extension's multiplicative group (order `p^2 - 1`); bare `x`
+  // only has order 200, far short of the full group.
+  fn generator() -> Self { Self([PlutoField::generator(), PlutoField::one()]) }
+}
+
+impl Add for PlutoExt2 {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self { Self([self.0[0] + rhs.0[0], self.0[1] + rhs.0[1]]) }
+}
+
+impl Sub for PlutoExt2 {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self { Self([self.0[0] - rhs.0[0], self.0[1] - rhs.0[1]]) }
+}
+
+impl Neg for PlutoExt2 {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output { Self([-self.0[0], -self.0[1]]) }
+}
+
+impl Mul for PlutoExt2 {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self {
+    let c0 = self.0[0] * rhs.0[0] + quadratic_non_residue() * self.0[1] * rhs.0[1];
+    let c1 = self.0[0] * rhs.0[1] + self.0[1] * rhs.0[0];
+    Self([c0, c1])
+  }
+}
+
+impl AddAssign for PlutoExt2 {
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl SubAssign for PlutoExt2 {
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl MulAssign for PlutoExt2 {
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl Sum for PlutoExt2 {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x + y).unwrap_or(Self::zero())
+  }
+}
+
+impl Product for PlutoExt2 {
+  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x * y).unwrap_or(Self::one())
   }
 }
 
@@ -188,7 +549,7 @@ mod tests {
     let a = PlutoField::new(100);
     let b = PlutoField::new(20);
     let c = a + b;
-    assert_eq!(c.value, 19);
+    assert_eq!(c.to_canonical_u32(), 19);
   }
 
   #[test]
@@ -196,7 +557,7 @@ mod tests {
     let a = PlutoField::new(10);
     let b = PlutoField::new(20);
     let c = a - b;
-    assert_eq!(c.value, 91);
+    assert_eq!(c.to_canonical_u32(), 91);
   }
 
   #[test]
@@ -205,7 +566,7 @@ mod tests {
     let b = PlutoField::new(20);
     let c = a * b;
     println!("c: {:?}", c);
-    assert_eq!(c.value, 99);
+    assert_eq!(c.to_canonical_u32(), 99);
   }
 
   #[test]
@@ -229,10 +590,10 @@ mod tests {
     let a = PlutoField::new(50);
     let b = PlutoField::new(60);
     let c = a + b;
-    assert_eq!(c.value, 9); // (50 + 60) % 101 = 9
+    assert_eq!(c.to_canonical_u32(), 9); // (50 + 60) % 101 = 9
 
     let d = c - a;
-    assert_eq!(d.value, 60); // (9 - 50) % 101 = 60
+    assert_eq!(d.to_canonical_u32(), 60); // (9 - 50) % 101 = 60
   }
 
   #[test]
@@ -240,7 +601,7 @@ mod tests {
     let a = PlutoField::new(10);
     let a_inv = a.inverse();
     let should_be_one = a * a_inv;
-    assert_eq!(should_be_one.value, 1);
+    assert_eq!(should_be_one.to_canonical_u32(), 1);
   }
 
   #[test]
@@ -256,14 +617,14 @@ mod tests {
   fn zero_multiplication() {
     let a = PlutoField::new(10);
     let zero = PlutoField::new(0);
-    assert_eq!((a * zero).value, 0);
+    assert_eq!((a * zero).to_canonical_u32(), 0);
   }
 
   #[test]
   fn negation() {
     let a = PlutoField::new(10);
     let neg_a = -a;
-    assert_eq!((a + neg_a).value, 0);
+    assert_eq!((a + neg_a).to_canonical_u32(), 0);
   }
 
   #[test]
@@ -301,6 +662,258 @@ mod tests {
   fn power_of_zero() {
     let a = PlutoField::new(0);
     let b = PlutoField::exp_u64_generic(a, 3);
-    assert_eq!(b.value, 0);
+    assert_eq!(b.to_canonical_u32(), 0);
+  }
+
+  #[test]
+  fn montgomery_round_trip() {
+    for n in 0..PlutoField::ORDER_U32 {
+      assert_eq!(PlutoField::new(n).to_canonical_u32(), n);
+    }
+  }
+
+  #[test]
+  fn montgomery_matches_modular_arithmetic() {
+    for a in 0..PlutoField::ORDER_U32 {
+      for b in 0..PlutoField::ORDER_U32 {
+        let (fa, fb) = (PlutoField::new(a), PlutoField::new(b));
+        assert_eq!((fa + fb).to_canonical_u32(), (a + b) % PlutoField::ORDER_U32);
+        assert_eq!((fa * fb).to_canonical_u32(), (a * b) % PlutoField::ORDER_U32);
+      }
+    }
+  }
+
+  #[test]
+  fn ext2_add_sub_neg() {
+    let a = PlutoExt2::new(PlutoField::new(3), PlutoField::new(5));
+    let b = PlutoExt2::new(PlutoField::new(10), PlutoField::new(20));
+    assert_eq!(a + b - b, a);
+    assert_eq!(a + (-a), PlutoExt2::zero());
+  }
+
+  #[test]
+  fn ext2_mul_matches_schoolbook() {
+    let a = PlutoExt2::new(PlutoField::new(3), PlutoField::new(5));
+    let b = PlutoExt2::new(PlutoField::new(10), PlutoField::new(20));
+    let w = quadratic_non_residue();
+    let expected = PlutoExt2::new(
+      PlutoField::new(3) * PlutoField::new(10) + w * PlutoField::new(5) * PlutoField::new(20),
+      PlutoField::new(3) * PlutoField::new(20) + PlutoField::new(5) * PlutoField::new(10),
+    );
+    assert_eq!(a * b, expected);
+  }
+
+  #[test]
+  fn ext2_inverse() {
+    let a = PlutoExt2::new(PlutoField::new(3), PlutoField::new(5));
+    let a_inv = a.inverse();
+    assert_eq!(a * a_inv, PlutoExt2::one());
+  }
+
+  #[test]
+  fn ext2_frobenius_is_conjugate() {
+    let a = PlutoExt2::new(PlutoField::new(3), PlutoField::new(5));
+    let conjugate = PlutoExt2::new(PlutoField::new(3), -PlutoField::new(5));
+    assert_eq!(a.frobenius(), conjugate);
+  }
+
+  #[test]
+  fn ext2_w_is_a_non_residue() {
+    // Confirm via Euler's criterion: a quadratic non-residue raised to (p - 1) / 2
+    // is -1.
+    let w = quadratic_non_residue();
+    assert_eq!(PlutoField::exp_u64_generic(w, 50), PlutoField::neg_one());
+  }
+
+  #[test]
+  fn ext2_generator_has_full_group_order() {
+    // The extension's multiplicative group has order p^2 - 1 = 10200; `generator()`
+    // must have exactly that order, not some proper divisor of it (as bare `x` would).
+    let g = PlutoExt2::generator();
+    let order = 101 * 101 - 1;
+    let mut acc = PlutoExt2::one();
+    for _ in 0..order {
+      acc *= g;
+    }
+    assert_eq!(acc, PlutoExt2::one());
+    for &divisor in &[2u64, 3, 5, 17] {
+      let exponent = order / divisor;
+      let mut acc = PlutoExt2::one();
+      for _ in 0..exponent {
+        acc *= g;
+      }
+      assert_ne!(acc, PlutoExt2::one(), "generator() has order dividing p^2 - 1, not equal to it");
+    }
+  }
+
+  #[test]
+  fn constant_time_eq() {
+    let a = PlutoField::new(10);
+    let b = PlutoField::new(10);
+    let c = PlutoField::new(11);
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(!bool::from(a.ct_eq(&c)));
+  }
+
+  #[test]
+  fn conditional_select() {
+    let a = PlutoField::new(10);
+    let b = PlutoField::new(20);
+    assert_eq!(PlutoField::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(PlutoField::conditional_select(&a, &b, Choice::from(1)), b);
+  }
+
+  #[test]
+  fn ct_try_inverse_matches_try_inverse() {
+    let a = PlutoField::new(10);
+    assert_eq!(a.ct_try_inverse().unwrap(), a.try_inverse().unwrap());
+    assert!(bool::from(PlutoField::zero().ct_try_inverse().is_none()));
+  }
+
+  #[test]
+  fn legendre_symbol() {
+    assert_eq!(PlutoField::zero().legendre(), 0);
+    assert_eq!(PlutoField::new(4).legendre(), 1); // 4 = 2^2 is a square
+    assert_eq!(PlutoField::new(10).legendre(), -1); // 10 is a non-residue mod 101
+  }
+
+  #[test]
+  fn sqrt_of_square() {
+    let root = PlutoField::new(2);
+    let a = root * root;
+    let sqrt = a.sqrt().unwrap();
+    assert_eq!(sqrt * sqrt, a);
+  }
+
+  #[test]
+  fn sqrt_of_zero() {
+    assert_eq!(PlutoField::zero().sqrt(), Some(PlutoField::zero()));
+  }
+
+  #[test]
+  fn sqrt_of_non_residue_is_none() {
+    assert_eq!(PlutoField::new(10).sqrt(), None);
   }
+
+  #[test]
+  fn batch_inverse_matches_individual_inverses() {
+    let mut elems: Vec<PlutoField> = (1..10).map(PlutoField::new).collect();
+    let expected: Vec<PlutoField> = elems.iter().map(|e| e.inverse()).collect();
+    PlutoField::batch_inverse(&mut elems);
+    assert_eq!(elems, expected);
+  }
+
+  #[test]
+  fn batch_inverse_skips_zeros() {
+    let mut elems = vec![PlutoField::new(3), PlutoField::zero(), PlutoField::new(7)];
+    let expected = vec![PlutoField::new(3).inverse(), PlutoField::zero(), PlutoField::new(7).inverse()];
+    PlutoField::batch_inverse(&mut elems);
+    assert_eq!(elems, expected);
+  }
+
+  #[test]
+  fn batch_inverse_to_vec_is_non_mutating() {
+    let elems: Vec<PlutoField> = (1..5).map(PlutoField::new).collect();
+    let inverses = PlutoField::batch_inverse_to_vec(&elems);
+    for (e, inv) in elems.iter().zip(inverses.iter()) {
+      assert_eq!(*e * *inv, PlutoField::one());
+    }
+  }
+
+  #[test]
+  fn two_adic_generator_has_expected_order() {
+    for bits in 0..=PlutoField::TWO_ADICITY {
+      let root = PlutoField::two_adic_generator(bits);
+      assert_eq!(F::exp_u64_generic(root, 1 << bits), F::one());
+      if bits > 0 {
+        assert_eq!(F::exp_u64_generic(root, 1 << (bits - 1)), F::neg_one());
+      }
+    }
+  }
+
+  #[test]
+  fn two_adic_subgroup_is_distinct_and_closed() {
+    let subgroup = PlutoField::two_adic_subgroup(PlutoField::TWO_ADICITY);
+    assert_eq!(subgroup.len(), 1 << PlutoField::TWO_ADICITY);
+    for (i, a) in subgroup.iter().enumerate() {
+      for b in &subgroup[i + 1..] {
+        assert_ne!(a, b);
+      }
+      for b in &subgroup {
+        assert!(subgroup.contains(&(*a * *b)));
+      }
+    }
+  }
+
+  #[test]
+  fn monty_field_is_generic_over_the_prime() {
+    type F7 = MontyField<7>;
+    for a in 0..7 {
+      for b in 0..7 {
+        let (fa, fb) = (F7::new(a), F7::new(b));
+        assert_eq!((fa + fb).to_canonical_u32(), (a + b) % 7);
+        assert_eq!((fa * fb).to_canonical_u32(), (a * b) % 7);
+      }
+    }
+    for a in 1..7 {
+      let fa = F7::new(a);
+      assert_eq!(fa * fa.inverse(), F7::one());
+    }
+  }
+
+  /// Sweeps odd primes up to the largest one the fixed `R = 2^MONTY_BITS = 128`
+  /// Montgomery modulus can safely serve (`(P-1)^2 < R·P`, satisfied up to `P = 127`;
+  /// `R` being a power of two also requires `P` odd), checking both the round trip and
+  /// multiplication against plain modular arithmetic. `MontyField<7>` alone is too small
+  /// a prime to have caught the overflow this guards against, so this covers the boundary
+  /// instead.
+  #[test]
+  fn monty_field_is_sound_up_to_the_largest_safe_prime() {
+    const PRIMES: [u32; 10] = [3, 5, 7, 11, 13, 37, 73, 97, 113, 127];
+
+    fn check<const P: u32>() {
+      for n in 0..P {
+        assert_eq!(MontyField::<P>::new(n).to_canonical_u32(), n);
+      }
+      for a in 0..P {
+        for b in 0..P {
+          let (fa, fb) = (MontyField::<P>::new(a), MontyField::<P>::new(b));
+          assert_eq!((fa + fb).to_canonical_u32(), (a + b) % P);
+          assert_eq!((fa * fb).to_canonical_u32(), (a * b) % P);
+        }
+      }
+    }
+
+    assert_eq!(PRIMES.last(), Some(&127), "keep this sweep anchored at the safe bound");
+    check::<3>();
+    check::<5>();
+    check::<7>();
+    check::<11>();
+    check::<13>();
+    check::<37>();
+    check::<73>();
+    check::<97>();
+    check::<113>();
+    check::<127>();
+  }
+
+  #[test]
+  fn new_reduces_out_of_range_values() {
+    assert_eq!(PlutoField::new(1000), PlutoField::new(91)); // both are 91 mod 101
+    assert_eq!(PlutoField::new(4_000_000_000).to_canonical_u32(), 4_000_000_000 % 101);
+  }
+
+  // `MontyField<P>` requires `P` odd and prime, since the fixed `R = 2^MONTY_BITS` is
+  // only invertible mod an odd modulus and a composite `P` has zero divisors;
+  // `MONTGOMERY_PRECONDITION` enforces both at monomorphization time rather than at
+  // runtime, so there's no `#[test]` to write for it — the crate is simply not supposed
+  // to build with such a `P`. Defining `_even_modulus_is_rejected` below, on its own,
+  // proves nothing: an unused function's body is never monomorphized, so it compiles
+  // cleanly even though `MontyField::<4>` is invalid. The `assert!` in
+  // `MONTGOMERY_PRECONDITION` only fires once something reachable actually *calls*
+  // `MontyField::<4>::new(0)` — confirmed by hand by adding a call to this function from
+  // a `#[test]`, which then fails the build with a compile-time `MONTGOMERY_PRECONDITION`
+  // error (not a runtime panic, and not some unrelated type error) rather than passing.
+  //
+  // fn _even_modulus_is_rejected() { let _ = MontyField::<4>::new(0); } // never called
 }
\ No newline at end of file